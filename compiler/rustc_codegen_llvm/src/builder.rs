@@ -15,6 +15,7 @@
 use rustc_codegen_ssa::MemFlags;
 use rustc_data_structures::small_c_str::SmallCStr;
 use rustc_hir::def_id::DefId;
+use rustc_middle::bug;
 use rustc_middle::ty::layout::TyAndLayout;
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::Span;
@@ -497,7 +498,7 @@ fn scalar_load_metadata<'a, 'll, 'tcx>(
             let b_offset = a.value.size(self).align_to(b.value.align(self).abi);
 
             let mut load = |i, scalar: &abi::Scalar, align| {
-                let llptr = self.struct_gep(place.llval, i as u64);
+                let llptr = self.scalar_pair_element_ptr(place.llval, place.layout, i);
                 let load = self.load(llptr, align);
                 scalar_load_metadata(self, load, scalar);
                 self.to_immediate_scalar(load, scalar)
@@ -665,6 +666,19 @@ fn struct_gep(&mut self, ptr: &'ll Value, idx: u64) -> &'ll Value {
         unsafe { llvm::LLVMBuildStructGEP(self.llbuilder, ptr, idx as c_uint, UNNAMED) }
     }
 
+    fn scalar_pair_element_ptr(
+        &mut self,
+        ptr: &'ll Value,
+        layout: TyAndLayout<'tcx>,
+        idx: usize,
+    ) -> &'ll Value {
+        if !layout.is_llvm_scalar_pair() || idx > 1 {
+            bug!("scalar_pair_element_ptr({}) called on layout `{:#?}`", idx, layout);
+        }
+        // In memory, LLVM represents a `ScalarPair` as a two-element struct.
+        self.struct_gep(ptr, idx as u64)
+    }
+
     /* Casts */
     fn trunc(&mut self, val: &'ll Value, dest_ty: &'ll Type) -> &'ll Value {
         unsafe { llvm::LLVMBuildTrunc(self.llbuilder, val, dest_ty, UNNAMED) }
@@ -56,7 +56,7 @@ pub fn size_and_align_of_dst<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
             // Recurse to get the size of the dynamically sized field (must be
             // the last field).
             let field_ty = layout.field(bx, i).ty;
-            let (unsized_size, mut unsized_align) = size_and_align_of_dst(bx, field_ty, info);
+            let (unsized_size, unsized_align) = size_and_align_of_dst(bx, field_ty, info);
 
             // FIXME (#26403, #27023): We should be adding padding
             // to `sized_size` (to accommodate the `unsized_align`
@@ -68,12 +68,7 @@ pub fn size_and_align_of_dst<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
             // Return the sum of sizes and max of aligns.
             let size = bx.add(sized_size, unsized_size);
 
-            // Packed types ignore the alignment of their fields.
-            if let ty::Adt(def, _) = t.kind() {
-                if def.repr.packed() {
-                    unsized_align = sized_align;
-                }
-            }
+            let unsized_align = cap_align_at_pack(bx, t, unsized_align);
 
             // Choose max of two known alignments (combined value must
             // be aligned according to more restrictive of the two).
@@ -112,3 +107,27 @@ pub fn size_and_align_of_dst<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
         }
     }
 }
+
+/// Caps `align`, the dynamic alignment of a field of `t`, at the packing value of `t`
+/// if it is a packed ADT.
+pub fn cap_align_at_pack<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
+    bx: &mut Bx,
+    t: Ty<'tcx>,
+    align: Bx::Value,
+) -> Bx::Value {
+    let pack = match t.kind() {
+        ty::Adt(def, _) => match def.repr.pack {
+            Some(pack) => pack.bytes(),
+            None => return align,
+        },
+        _ => return align,
+    };
+    match bx.const_to_opt_u128(align, false) {
+        Some(align) => bx.const_usize(std::cmp::min(align as u64, pack)),
+        None => {
+            let pack = bx.const_usize(pack);
+            let cmp = bx.icmp(IntPredicate::IntULT, align, pack);
+            bx.select(cmp, align, pack)
+        }
+    }
+}
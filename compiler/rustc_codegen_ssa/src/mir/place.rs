@@ -10,7 +10,7 @@
 use rustc_middle::mir::tcx::PlaceTy;
 use rustc_middle::ty::layout::{HasTyCtxt, TyAndLayout};
 use rustc_middle::ty::{self, Ty};
-use rustc_target::abi::{Abi, Align, FieldsShape, Int, TagEncoding};
+use rustc_target::abi::{Abi, Align, FieldsShape, Int, Pointer, TagEncoding};
 use rustc_target::abi::{LayoutOf, VariantIdx, Variants};
 
 #[derive(Copy, Clone, Debug)]
@@ -43,8 +43,6 @@ pub fn new_sized_aligned(
         PlaceRef { llval, llextra: None, layout, align }
     }
 
-    // FIXME(eddyb) pass something else for the name so no work is done
-    // unless LLVM IR names are turned on (e.g. for `--emit=llvm-ir`).
     pub fn alloca<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
         bx: &mut Bx,
         layout: TyAndLayout<'tcx>,
@@ -55,8 +53,6 @@ pub fn alloca<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
     }
 
     /// Returns a place for an indirect reference to an unsized place.
-    // FIXME(eddyb) pass something else for the name so no work is done
-    // unless LLVM IR names are turned on (e.g. for `--emit=llvm-ir`).
     pub fn alloca_unsized_indirect<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
         bx: &mut Bx,
         layout: TyAndLayout<'tcx>,
@@ -94,31 +90,48 @@ pub fn project_field<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
 
         let mut simple = || {
             let llval = match self.layout.abi {
+                Abi::ScalarPair(ref a, ref b) if !field.is_zst() => {
+                    // Fields may be reordered or interleaved with ZSTs, so the pair
+                    // component is picked by the field's offset, not its index.
+                    let b_offset = a.value.size(bx.cx()).align_to(b.value.align(bx.cx()).abi);
+                    let idx = if offset.bytes() == 0 {
+                        0
+                    } else if offset == b_offset {
+                        1
+                    } else {
+                        bug!(
+                            "offset of non-ZST field `{:?}` does not match layout `{:#?}`",
+                            field,
+                            self.layout
+                        );
+                    };
+                    bx.scalar_pair_element_ptr(self.llval, self.layout, idx)
+                }
                 _ if offset.bytes() == 0 => {
                     // Unions and newtypes only use an offset of 0.
-                    // Also handles the first field of Scalar, ScalarPair, and Vector layouts.
+                    // Also handles the first field of Scalar and Vector layouts.
                     self.llval
                 }
-                Abi::ScalarPair(ref a, ref b)
-                    if offset == a.value.size(bx.cx()).align_to(b.value.align(bx.cx()).abi) =>
-                {
-                    // Offset matches second field.
-                    bx.struct_gep(self.llval, 1)
-                }
                 Abi::Scalar(_) | Abi::ScalarPair(..) | Abi::Vector { .. } if field.is_zst() => {
                     // ZST fields are not included in Scalar, ScalarPair, and Vector layouts, so manually offset the pointer.
                     let byte_ptr = bx.pointercast(self.llval, bx.cx().type_i8p());
                     bx.gep(byte_ptr, &[bx.const_usize(offset.bytes())])
                 }
-                Abi::Scalar(_) | Abi::ScalarPair(..) => {
-                    // All fields of Scalar and ScalarPair layouts must have been handled by this point.
-                    // Vector layouts have additional fields for each element of the vector, so don't panic in that case.
+                Abi::Scalar(_) => {
+                    // All fields of Scalar layouts must have been handled by this point.
                     bug!(
                         "offset of non-ZST field `{:?}` does not match layout `{:#?}`",
                         field,
                         self.layout
                     );
                 }
+                Abi::Vector { .. } => {
+                    // Not every backend can GEP into a vector type, so offset a pointer to
+                    // the element type instead (vector elements are unpadded).
+                    let elem_ptr_ty = bx.cx().type_ptr_to(bx.cx().backend_type(field));
+                    let elem_ptr = bx.pointercast(self.llval, elem_ptr_ty);
+                    bx.inbounds_gep(elem_ptr, &[bx.cx().const_usize(ix as u64)])
+                }
                 _ => bx.struct_gep(self.llval, bx.cx().backend_field_index(self.layout, ix)),
             };
             PlaceRef {
@@ -133,7 +146,7 @@ pub fn project_field<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
         // Simple cases, which don't need DST adjustment:
         //   * no metadata available - just log the case
         //   * known alignment - sized types, `[T]`, `str` or a foreign type
-        //   * packed struct - there is no alignment padding
+        //   * `packed(1)` struct - there is no alignment padding
         match field.ty.kind() {
             _ if self.llextra.is_none() => {
                 debug!(
@@ -144,14 +157,7 @@ pub fn project_field<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
             }
             _ if !field.is_unsized() => return simple(),
             ty::Slice(..) | ty::Str | ty::Foreign(..) => return simple(),
-            ty::Adt(def, _) => {
-                if def.repr.packed() {
-                    // FIXME(eddyb) generalize the adjustment when we
-                    // start supporting packing to larger alignments.
-                    assert_eq!(self.layout.align.abi.bytes(), 1);
-                    return simple();
-                }
-            }
+            ty::Adt(def, _) if def.repr.pack == Some(Align::ONE) => return simple(),
             _ => {}
         }
 
@@ -177,6 +183,7 @@ pub fn project_field<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
 
         // Get the alignment of the field
         let (_, unsized_align) = glue::size_and_align_of_dst(bx, field.ty, meta);
+        let unsized_align = glue::cap_align_at_pack(bx, self.layout.ty, unsized_align);
 
         // Bump the unaligned offset up to the appropriate alignment
         let offset = round_up_const_value_to_alignment(bx, unaligned_offset, unsized_align);
@@ -243,8 +250,15 @@ pub fn codegen_get_discr<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
             TagEncoding::Niche { dataful_variant, ref niche_variants, niche_start } => {
                 // Rebase from niche values to discriminants, and check
                 // whether the result is in range for the niche variants.
-                let niche_llty = bx.cx().immediate_backend_type(tag.layout);
-                let tag = tag.immediate();
+                // Pointer niches are handled as `isize`, so that the arithmetic and
+                // comparisons below work the same for every niche primitive.
+                let (tag, niche_llty) = match tag_scalar.value {
+                    Pointer => {
+                        let isize_llty = bx.cx().type_isize();
+                        (bx.ptrtoint(tag.immediate(), isize_llty), isize_llty)
+                    }
+                    _ => (tag.immediate(), bx.cx().immediate_backend_type(tag.layout)),
+                };
 
                 // We first compute the "relative discriminant" (wrt `niche_variants`),
                 // that is, if `n = niche_variants.end() - niche_variants.start()`,
@@ -256,18 +270,15 @@ pub fn codegen_get_discr<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
                 // that might not fit in the same type, on top of needing an extra
                 // comparison (see also the comment on `let niche_discr`).
                 let relative_discr = if niche_start == 0 {
-                    // Avoid subtracting `0`, which wouldn't work for pointers.
-                    // FIXME(eddyb) check the actual primitive type here.
+                    // Avoid emitting a no-op subtraction.
                     tag
                 } else {
                     bx.sub(tag, bx.cx().const_uint_big(niche_llty, niche_start))
                 };
                 let relative_max = niche_variants.end().as_u32() - niche_variants.start().as_u32();
                 let is_niche = if relative_max == 0 {
-                    // Avoid calling `const_uint`, which wouldn't work for pointers.
-                    // Also use canonical == 0 instead of non-canonical u<= 0.
-                    // FIXME(eddyb) check the actual primitive type here.
-                    bx.icmp(IntPredicate::IntEQ, relative_discr, bx.cx().const_null(niche_llty))
+                    // Use canonical == 0 instead of non-canonical u<= 0.
+                    bx.icmp(IntPredicate::IntEQ, relative_discr, bx.cx().const_uint(niche_llty, 0))
                 } else {
                     let relative_max = bx.cx().const_uint(niche_llty, relative_max as u64);
                     bx.icmp(IntPredicate::IntULE, relative_discr, relative_max)
@@ -332,6 +343,7 @@ pub fn codegen_set_discr<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
                 );
             }
             Variants::Multiple {
+                ref tag,
                 tag_encoding:
                     TagEncoding::Niche { dataful_variant, ref niche_variants, niche_start },
                 tag_field,
@@ -352,12 +364,13 @@ pub fn codegen_set_discr<Bx: BuilderMethods<'a, 'tcx, Value = V>>(
                     let niche_llty = bx.cx().immediate_backend_type(niche.layout);
                     let niche_value = variant_index.as_u32() - niche_variants.start().as_u32();
                     let niche_value = (niche_value as u128).wrapping_add(niche_start);
-                    // FIXME(eddyb): check the actual primitive type here.
-                    let niche_llval = if niche_value == 0 {
-                        // HACK(eddyb): using `c_null` as it works on all types.
-                        bx.cx().const_null(niche_llty)
-                    } else {
-                        bx.cx().const_uint_big(niche_llty, niche_value)
+                    let niche_llval = match tag.value {
+                        Pointer if niche_value == 0 => bx.cx().const_null(niche_llty),
+                        Pointer => {
+                            let addr = bx.cx().const_uint_big(bx.cx().type_isize(), niche_value);
+                            bx.inttoptr(addr, niche_llty)
+                        }
+                        _ => bx.cx().const_uint_big(niche_llty, niche_value),
                     };
                     OperandValue::Immediate(niche_llval).store(bx, niche);
                 }
@@ -433,87 +446,80 @@ pub fn codegen_place(
         let cx = self.cx;
         let tcx = self.cx.tcx();
 
-        let result = match place_ref {
-            mir::PlaceRef { local, projection: [] } => match self.locals[local] {
-                LocalRef::Place(place) => {
-                    return place;
-                }
-                LocalRef::UnsizedPlace(place) => {
-                    return bx.load_operand(place).deref(cx);
-                }
-                LocalRef::Operand(..) => {
+        let mut base = 0;
+        let mut cg_base = match self.locals[place_ref.local] {
+            LocalRef::Place(place) => place,
+            LocalRef::UnsizedPlace(place) => bx.load_operand(place).deref(cx),
+            LocalRef::Operand(..) => {
+                // An operand local can only be used as a place behind a `Deref`, so load the
+                // pointer from the projection prefix leading up to the first one.
+                if let Some(deref_idx) =
+                    place_ref.projection.iter().position(|elem| *elem == mir::ProjectionElem::Deref)
+                {
+                    base = deref_idx + 1;
+                    self.codegen_consume(
+                        bx,
+                        mir::PlaceRef {
+                            local: place_ref.local,
+                            projection: &place_ref.projection[..deref_idx],
+                        },
+                    )
+                    .deref(bx.cx())
+                } else {
                     bug!("using operand local {:?} as place", place_ref);
                 }
-            },
-            mir::PlaceRef { local, projection: [proj_base @ .., mir::ProjectionElem::Deref] } => {
-                // Load the pointer from its location.
-                self.codegen_consume(bx, mir::PlaceRef { local, projection: proj_base })
-                    .deref(bx.cx())
             }
-            mir::PlaceRef { local, projection: &[ref proj_base @ .., elem] } => {
-                // FIXME turn this recursion into iteration
-                let cg_base =
-                    self.codegen_place(bx, mir::PlaceRef { local, projection: proj_base });
-
-                match elem {
-                    mir::ProjectionElem::Deref => bug!(),
-                    mir::ProjectionElem::Field(ref field, _) => {
-                        cg_base.project_field(bx, field.index())
-                    }
-                    mir::ProjectionElem::Index(index) => {
-                        let index = &mir::Operand::Copy(mir::Place::from(index));
-                        let index = self.codegen_operand(bx, index);
-                        let llindex = index.immediate();
-                        cg_base.project_index(bx, llindex)
-                    }
-                    mir::ProjectionElem::ConstantIndex {
-                        offset,
-                        from_end: false,
-                        min_length: _,
-                    } => {
-                        let lloffset = bx.cx().const_usize(offset as u64);
-                        cg_base.project_index(bx, lloffset)
-                    }
-                    mir::ProjectionElem::ConstantIndex {
-                        offset,
-                        from_end: true,
-                        min_length: _,
-                    } => {
-                        let lloffset = bx.cx().const_usize(offset as u64);
-                        let lllen = cg_base.len(bx.cx());
-                        let llindex = bx.sub(lllen, lloffset);
-                        cg_base.project_index(bx, llindex)
+        };
+        for &elem in place_ref.projection[base..].iter() {
+            cg_base = match elem {
+                mir::ProjectionElem::Deref => bx.load_operand(cg_base).deref(bx.cx()),
+                mir::ProjectionElem::Field(ref field, _) => {
+                    cg_base.project_field(bx, field.index())
+                }
+                mir::ProjectionElem::Index(index) => {
+                    let index = &mir::Operand::Copy(mir::Place::from(index));
+                    let index = self.codegen_operand(bx, index);
+                    let llindex = index.immediate();
+                    cg_base.project_index(bx, llindex)
+                }
+                mir::ProjectionElem::ConstantIndex { offset, from_end: false, min_length: _ } => {
+                    let lloffset = bx.cx().const_usize(offset as u64);
+                    cg_base.project_index(bx, lloffset)
+                }
+                mir::ProjectionElem::ConstantIndex { offset, from_end: true, min_length: _ } => {
+                    let lloffset = bx.cx().const_usize(offset as u64);
+                    let lllen = cg_base.len(bx.cx());
+                    let llindex = bx.sub(lllen, lloffset);
+                    cg_base.project_index(bx, llindex)
+                }
+                mir::ProjectionElem::Subslice { from, to, from_end } => {
+                    let mut subslice = cg_base.project_index(bx, bx.cx().const_usize(from as u64));
+                    let projected_ty =
+                        PlaceTy::from_ty(cg_base.layout.ty).projection_ty(tcx, elem).ty;
+                    subslice.layout = bx.cx().layout_of(self.monomorphize(projected_ty));
+
+                    if subslice.layout.is_unsized() {
+                        assert!(from_end, "slice subslices should be `from_end`");
+                        subslice.llextra = Some(bx.sub(
+                            cg_base.llextra.unwrap(),
+                            bx.cx().const_usize((from as u64) + (to as u64)),
+                        ));
                     }
-                    mir::ProjectionElem::Subslice { from, to, from_end } => {
-                        let mut subslice =
-                            cg_base.project_index(bx, bx.cx().const_usize(from as u64));
-                        let projected_ty =
-                            PlaceTy::from_ty(cg_base.layout.ty).projection_ty(tcx, elem).ty;
-                        subslice.layout = bx.cx().layout_of(self.monomorphize(projected_ty));
-
-                        if subslice.layout.is_unsized() {
-                            assert!(from_end, "slice subslices should be `from_end`");
-                            subslice.llextra = Some(bx.sub(
-                                cg_base.llextra.unwrap(),
-                                bx.cx().const_usize((from as u64) + (to as u64)),
-                            ));
-                        }
 
-                        // Cast the place pointer type to the new
-                        // array or slice type (`*[%_; new_len]`).
-                        subslice.llval = bx.pointercast(
-                            subslice.llval,
-                            bx.cx().type_ptr_to(bx.cx().backend_type(subslice.layout)),
-                        );
+                    // Cast the place pointer type to the new
+                    // array or slice type (`*[%_; new_len]`).
+                    subslice.llval = bx.pointercast(
+                        subslice.llval,
+                        bx.cx().type_ptr_to(bx.cx().backend_type(subslice.layout)),
+                    );
 
-                        subslice
-                    }
-                    mir::ProjectionElem::Downcast(_, v) => cg_base.project_downcast(bx, v),
+                    subslice
                 }
-            }
-        };
-        debug!("codegen_place(place={:?}) => {:?}", place_ref, result);
-        result
+                mir::ProjectionElem::Downcast(_, v) => cg_base.project_downcast(bx, v),
+            };
+        }
+        debug!("codegen_place(place={:?}) => {:?}", place_ref, cg_base);
+        cg_base
     }
 
     pub fn monomorphized_place_ty(&self, place_ref: mir::PlaceRef<'tcx>) -> Ty<'tcx> {
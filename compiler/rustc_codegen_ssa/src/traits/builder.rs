@@ -173,6 +173,14 @@ fn atomic_store(
     fn gep(&mut self, ptr: Self::Value, indices: &[Self::Value]) -> Self::Value;
     fn inbounds_gep(&mut self, ptr: Self::Value, indices: &[Self::Value]) -> Self::Value;
     fn struct_gep(&mut self, ptr: Self::Value, idx: u64) -> Self::Value;
+    /// Returns a pointer to component `idx` (`0` or `1`) of the `ScalarPair` value of
+    /// type `layout` that `ptr` points to.
+    fn scalar_pair_element_ptr(
+        &mut self,
+        ptr: Self::Value,
+        layout: TyAndLayout<'tcx>,
+        idx: usize,
+    ) -> Self::Value;
 
     fn trunc(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value;
     fn sext(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value;
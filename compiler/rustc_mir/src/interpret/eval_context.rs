@@ -623,6 +623,12 @@ pub(super) fn size_and_align_of(
                 // is unfixed, we do not yet add the necessary padding
                 // here. But this is where the add would go.)
 
+                // Packed types cap the alignment of their fields at the packing value.
+                let unsized_align = match layout.ty.kind() {
+                    ty::Adt(def, _) => def.repr.pack.map_or(unsized_align, |p| unsized_align.min(p)),
+                    _ => unsized_align,
+                };
+
                 // Return the sum of sizes and max of aligns.
                 let size = sized_size + unsized_size; // `Size` addition
 
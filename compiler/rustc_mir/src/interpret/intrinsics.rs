@@ -4,6 +4,7 @@
 
 use std::convert::TryFrom;
 
+use rustc_apfloat::Float;
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::{
     self,
@@ -462,6 +463,18 @@ pub fn emulate_intrinsic(
                 );
                 self.copy_op(&self.operand_index(&args[0], index)?, dest)?;
             }
+            sym::minnumf32 | sym::maxnumf32 => {
+                let a = self.read_scalar(&args[0])?.to_f32()?;
+                let b = self.read_scalar(&args[1])?.to_f32()?;
+                let res = if intrinsic_name == sym::minnumf32 { a.min(b) } else { a.max(b) };
+                self.write_scalar(Scalar::from_f32(res), dest)?;
+            }
+            sym::minnumf64 | sym::maxnumf64 => {
+                let a = self.read_scalar(&args[0])?.to_f64()?;
+                let b = self.read_scalar(&args[1])?.to_f64()?;
+                let res = if intrinsic_name == sym::minnumf64 { a.min(b) } else { a.max(b) };
+                self.write_scalar(Scalar::from_f64(res), dest)?;
+            }
             sym::likely | sym::unlikely => {
                 // These just return their argument
                 self.copy_op(&args[0], dest)?;
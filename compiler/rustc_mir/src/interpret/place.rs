@@ -427,6 +427,11 @@ pub fn mplace_field(
                     "cannot compute offset for extern type field at non-0 offset"
                 ),
             };
+            // A packed struct caps the alignment of its fields at the packing value.
+            let align = match base.layout.ty.kind() {
+                ty::Adt(def, _) => def.repr.pack.map_or(align, |p| align.min(p)),
+                _ => align,
+            };
             (base.meta, offset.align_to(align))
         } else {
             // base.meta could be present; we might be accessing a sized field of an unsized
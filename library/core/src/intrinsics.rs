@@ -1312,21 +1312,25 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     ///
     /// The stabilized version of this intrinsic is
     /// [`f32::min`]
+    #[rustc_const_unstable(feature = "const_float_minmax", issue = "none")]
     pub fn minnumf32(x: f32, y: f32) -> f32;
     /// Returns the minimum of two `f64` values.
     ///
     /// The stabilized version of this intrinsic is
     /// [`f64::min`]
+    #[rustc_const_unstable(feature = "const_float_minmax", issue = "none")]
     pub fn minnumf64(x: f64, y: f64) -> f64;
     /// Returns the maximum of two `f32` values.
     ///
     /// The stabilized version of this intrinsic is
     /// [`f32::max`]
+    #[rustc_const_unstable(feature = "const_float_minmax", issue = "none")]
     pub fn maxnumf32(x: f32, y: f32) -> f32;
     /// Returns the maximum of two `f64` values.
     ///
     /// The stabilized version of this intrinsic is
     /// [`f64::max`]
+    #[rustc_const_unstable(feature = "const_float_minmax", issue = "none")]
     pub fn maxnumf64(x: f64, y: f64) -> f64;
 
     /// Copies the sign from `y` to `x` for `f32` values.
@@ -0,0 +1,18 @@
+// Check that the discriminant of an enum whose niche is a pointer is read by
+// converting the pointer to an integer, rather than comparing it against an
+// integer constant of pointer type.
+//
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+// CHECK-LABEL: @opt_ref_is_some
+#[no_mangle]
+pub fn opt_ref_is_some(x: Option<&u8>) -> bool {
+    // CHECK: [[ADDR:%.*]] = ptrtoint {{.*}} to [[USIZE:i[0-9]+]]
+    // CHECK: icmp eq [[USIZE]] [[ADDR]], 0
+    match x {
+        Some(_) => true,
+        None => false,
+    }
+}
@@ -0,0 +1,22 @@
+// Check that fields of a SIMD vector are projected by offsetting a pointer to the
+// element type, rather than by a struct GEP into the vector type.
+//
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#![feature(repr_simd)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct f32x4(pub f32, pub f32, pub f32, pub f32);
+
+// CHECK-LABEL: @third
+#[no_mangle]
+pub fn third(x: &f32x4) -> f32 {
+    // CHECK-NOT: getelementptr inbounds <4 x float>
+    // CHECK: [[ELEMS:%.*]] = bitcast <4 x float>* %{{.*}} to float*
+    // CHECK: getelementptr inbounds float, float* [[ELEMS]], {{i32|i64}} 2
+    x.2
+}
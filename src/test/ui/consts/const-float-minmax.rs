@@ -0,0 +1,45 @@
+// compile-flags: -Zmir-opt-level=0
+// run-pass
+
+#![feature(const_panic)]
+#![feature(core_intrinsics)]
+#![feature(const_float_minmax)]
+
+use std::intrinsics::{maxnumf32, maxnumf64, minnumf32, minnumf64};
+
+// Don't promote
+const fn nop<T>(x: T) -> T { x }
+
+macro_rules! const_assert {
+    ($a:expr, $b:expr) => {
+        {
+            const _: () = assert!(unsafe { $a } == $b);
+            assert_eq!(nop(unsafe { $a }), nop($b));
+        }
+    };
+}
+
+fn f32() {
+    const_assert!(minnumf32(1.0, 2.0), 1.0);
+    const_assert!(maxnumf32(1.0, 2.0), 2.0);
+    const_assert!(minnumf32(-0.5, f32::INFINITY), -0.5);
+    const_assert!(maxnumf32(f32::NEG_INFINITY, -0.5), -0.5);
+    // A NaN operand is ignored.
+    const_assert!(minnumf32(f32::NAN, 2.0), 2.0);
+    const_assert!(maxnumf32(1.0, f32::NAN), 1.0);
+}
+
+fn f64() {
+    const_assert!(minnumf64(1.0, 2.0), 1.0);
+    const_assert!(maxnumf64(1.0, 2.0), 2.0);
+    const_assert!(minnumf64(-0.5, f64::INFINITY), -0.5);
+    const_assert!(maxnumf64(f64::NEG_INFINITY, -0.5), -0.5);
+    // A NaN operand is ignored.
+    const_assert!(minnumf64(f64::NAN, 2.0), 2.0);
+    const_assert!(maxnumf64(1.0, f64::NAN), 1.0);
+}
+
+fn main() {
+    f32();
+    f64();
+}
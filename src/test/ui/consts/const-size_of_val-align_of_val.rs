@@ -19,6 +19,11 @@ union Ugh {
     _b: Bar,
 }
 
+struct Tail<T: ?Sized> {
+    _a: u16,
+    _b: T,
+}
+
 const FOO: Foo = Foo(4);
 const BAR: Bar = Bar { _x: 4, _y: 1, _z: 2 };
 const UGH: Ugh = Ugh { _a: [0; 3] };
@@ -33,6 +38,14 @@ union Ugh {
 
 const SIZE_OF_SLICE: usize = mem::size_of_val("foobar".as_bytes());
 
+const DYN: &dyn Send = &BAR;
+const SIZE_OF_DYN: usize = mem::size_of_val(DYN);
+const ALIGN_OF_DYN: usize = mem::align_of_val(DYN);
+
+const TAIL: &Tail<[u8]> = &Tail { _a: 0, _b: [1, 2, 3] };
+const SIZE_OF_TAIL: usize = mem::size_of_val(TAIL);
+const ALIGN_OF_TAIL: usize = mem::align_of_val(TAIL);
+
 const SIZE_OF_DANGLING: usize = unsafe { mem::size_of_val_raw(0x100 as *const i32) };
 const ALIGN_OF_DANGLING: usize = unsafe { mem::align_of_val_raw(0x100 as *const i16) };
 
@@ -49,4 +62,11 @@ fn main() {
     assert_eq!(ALIGN_OF_DANGLING, mem::align_of::<i16>());
 
     assert_eq!(SIZE_OF_SLICE, "foobar".len());
+
+    assert_eq!(SIZE_OF_DYN, mem::size_of::<Bar>());
+    assert_eq!(ALIGN_OF_DYN, mem::align_of::<Bar>());
+
+    let tail: &Tail<[u8]> = &Tail { _a: 0, _b: [1, 2, 3] };
+    assert_eq!(SIZE_OF_TAIL, mem::size_of_val(tail));
+    assert_eq!(ALIGN_OF_TAIL, mem::align_of_val(tail));
 }
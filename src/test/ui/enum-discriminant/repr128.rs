@@ -23,6 +23,18 @@ enum Unsigned {
     Next,
 }
 
+// `repr(i128)`/`repr(u128)` disable the univariant layout optimization, so these
+// still get a direct tag rather than `Variants::Single`.
+#[repr(i128)]
+enum SingleSigned {
+    Only = i128::MIN + 3,
+}
+
+#[repr(u128)]
+enum SingleUnsigned {
+    Only = u128::MAX - 1,
+}
+
 fn discr<T, U>(v: T, value: U)
 where
     <T as DiscriminantKind>::Discriminant: PartialEq<U>,
@@ -42,4 +54,13 @@ fn main() {
     discr(Unsigned::Staircase, 0x01_02_03_04_05_06_07_08_09_0a_0b_0c_0d_0e_0f);
     discr(Unsigned::U64Limit, u64::MAX as u128 + 1);
     discr(Unsigned::Next, u64::MAX as u128 + 2);
+
+    discr(SingleSigned::Only, i128::MIN + 3);
+    discr(SingleUnsigned::Only, u128::MAX - 1);
+
+    assert_eq!(Signed::BigNegative as i128, i128::MIN);
+    assert_eq!(Signed::Next as i128, i128::MIN + 1);
+    assert_eq!(Unsigned::U64Limit as u128, u64::MAX as u128 + 1);
+    assert_eq!(SingleSigned::Only as i128, i128::MIN + 3);
+    assert_eq!(SingleUnsigned::Only as u128, u128::MAX - 1);
 }
@@ -0,0 +1,119 @@
+// run-pass
+#![allow(dead_code)]
+#![feature(raw_ref_op)]
+
+// Check the size, alignment and tail offset of packed structs whose last field is
+// an unsized struct that is more aligned than the packing allows, both on their own
+// and as the unsized field of another struct.
+
+use std::mem;
+use std::ptr;
+
+#[repr(C)]
+struct Tail32 {
+    len: u8,
+    data: [u32],
+}
+
+#[repr(C)]
+struct Tail32Sized {
+    len: u8,
+    data: [u32; 3],
+}
+
+#[repr(C)]
+struct Tail64 {
+    len: u8,
+    data: [u64],
+}
+
+#[repr(C)]
+struct Tail64Sized {
+    len: u8,
+    data: [u64; 3],
+}
+
+#[repr(C, packed(2))]
+struct Packed2 {
+    a: u8,
+    tail: Tail32,
+}
+
+#[repr(C, packed(2))]
+struct Packed2Sized {
+    a: u8,
+    tail: Tail32Sized,
+}
+
+#[repr(C, packed(4))]
+struct Packed4 {
+    a: u8,
+    tail: Tail64,
+}
+
+#[repr(C, packed(4))]
+struct Packed4Sized {
+    a: u8,
+    tail: Tail64Sized,
+}
+
+#[repr(C)]
+struct Wrap<T: ?Sized> {
+    b: u8,
+    inner: T,
+}
+
+fn offset_of<T: ?Sized, U: ?Sized>(base: *const T, field: *const U) -> usize {
+    field as *const u8 as usize - base as *const u8 as usize
+}
+
+fn packed2() {
+    let sized = Packed2Sized { a: 1, tail: Tail32Sized { len: 3, data: [10, 20, 30] } };
+    let p = ptr::slice_from_raw_parts(&sized as *const Packed2Sized as *const u32, 3)
+        as *const Packed2;
+    unsafe {
+        assert_eq!(mem::size_of_val(&*p), 18);
+        assert_eq!(mem::size_of_val(&*p), mem::size_of::<Packed2Sized>());
+        assert_eq!(mem::align_of_val(&*p), 2);
+        assert_eq!(offset_of(p, &raw const (*p).tail), 2);
+        assert_eq!((*p).tail.len, 3);
+    }
+}
+
+fn packed4() {
+    let sized = Packed4Sized { a: 1, tail: Tail64Sized { len: 3, data: [10, 20, 30] } };
+    let p = ptr::slice_from_raw_parts(&sized as *const Packed4Sized as *const u64, 3)
+        as *const Packed4;
+    unsafe {
+        assert_eq!(mem::size_of_val(&*p), mem::size_of::<Packed4Sized>());
+        assert_eq!(mem::align_of_val(&*p), mem::align_of::<Packed4Sized>());
+        assert_eq!(mem::align_of_val(&*p), 4);
+        assert_eq!(offset_of(p, &raw const (*p).tail), 4);
+        assert_eq!((*p).tail.len, 3);
+    }
+}
+
+fn wrapped_packed2() {
+    let sized = Wrap {
+        b: 2,
+        inner: Packed2Sized { a: 1, tail: Tail32Sized { len: 3, data: [10, 20, 30] } },
+    };
+    let p = ptr::slice_from_raw_parts(&sized as *const Wrap<Packed2Sized> as *const u32, 3)
+        as *const Wrap<Packed2>;
+    unsafe {
+        assert_eq!(mem::size_of_val(&*p), 20);
+        assert_eq!(mem::size_of_val(&*p), mem::size_of::<Wrap<Packed2Sized>>());
+        assert_eq!(mem::align_of_val(&*p), 2);
+        let inner = &raw const (*p).inner;
+        assert_eq!(offset_of(p, inner), 2);
+        assert_eq!(mem::size_of_val(&*inner), 18);
+        assert_eq!((*p).inner.a, 1);
+        assert_eq!((*p).inner.tail.len, 3);
+    }
+}
+
+pub fn main() {
+    packed2();
+    packed4();
+    wrapped_packed2();
+}